@@ -0,0 +1,194 @@
+pub struct ScrollState {
+    selected: Option<usize>,
+    n_rows: usize,
+    max_n_rows_to_display: usize,
+    offset: usize,
+    // Kept at most `max_n_rows_to_display / 2` so it never forces an empty viewport.
+    max_scroll_padding: usize,
+}
+
+impl ScrollState {
+    pub fn new(
+        n_rows: usize,
+        selected: Option<usize>,
+        max_n_rows_to_display: usize,
+        max_scroll_padding: usize,
+    ) -> Self {
+        let mut slf = Self {
+            selected,
+            n_rows,
+            max_n_rows_to_display,
+            offset: 0,
+            max_scroll_padding,
+        };
+        slf.clamp_padding();
+        slf.update_offset();
+        slf
+    }
+
+    fn clamp_padding(&mut self) {
+        self.max_scroll_padding = self.max_scroll_padding.min(self.max_n_rows_to_display / 2);
+    }
+
+    #[inline]
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    pub fn max_n_rows_to_display(&self) -> usize {
+        self.max_n_rows_to_display
+    }
+
+    pub fn set_max_n_rows_to_display(&mut self, max_n_rows_to_display: usize) {
+        self.max_n_rows_to_display = max_n_rows_to_display;
+        self.clamp_padding();
+        self.update_offset();
+    }
+
+    pub fn set_n_rows(&mut self, n_rows: usize) {
+        self.n_rows = n_rows;
+
+        if let Some(selected) = self.selected {
+            if selected >= n_rows {
+                self.selected = n_rows.checked_sub(1);
+            }
+        }
+
+        self.update_offset();
+    }
+
+    /// Set the selected row directly, e.g. after a filter or search narrows the rows.
+    pub fn select(&mut self, selected: Option<usize>) {
+        self.selected = selected;
+        self.update_offset();
+    }
+
+    /// The highest offset that doesn't scroll past the end of the list.
+    fn max_offset(&self) -> usize {
+        self.n_rows.saturating_sub(self.max_n_rows_to_display)
+    }
+
+    // Keep `selected` at least `max_scroll_padding` rows away from the viewport's top and
+    // bottom edges, unless there aren't enough rows on that side for padding to make sense.
+    // If the viewport is too short to hold both paddings (e.g. a padding of 5 in a 10-row
+    // viewport), the bottom padding is kept and the top padding is one row short.
+    fn update_offset(&mut self) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+
+        let top_padding = self.max_scroll_padding.min(selected);
+        let bottom_padding = self
+            .max_scroll_padding
+            .min(self.n_rows.saturating_sub(1 + selected));
+
+        let max_offset_for_selection = selected.saturating_sub(top_padding);
+        let min_offset_for_selection = (selected + bottom_padding)
+            .saturating_sub(self.max_n_rows_to_display.saturating_sub(1));
+
+        self.offset = self
+            .offset
+            .max(min_offset_for_selection)
+            .min(max_offset_for_selection.max(min_offset_for_selection))
+            .min(self.max_offset());
+    }
+
+    pub fn select_next(&mut self) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+
+        if selected + 1 < self.n_rows {
+            self.selected = Some(selected + 1);
+            self.update_offset();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+
+        if selected > 0 {
+            self.selected = Some(selected - 1);
+            self.update_offset();
+        }
+    }
+
+    pub fn select_first(&mut self) {
+        if self.n_rows > 0 {
+            self.selected = Some(0);
+            self.update_offset();
+        }
+    }
+
+    pub fn select_last(&mut self) {
+        if self.n_rows > 0 {
+            self.selected = Some(self.n_rows - 1);
+            self.update_offset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScrollState;
+
+    #[test]
+    fn fewer_rows_than_viewport() {
+        let mut scroll_state = ScrollState::new(3, Some(0), 10, 2);
+        assert_eq!(scroll_state.offset(), 0);
+
+        scroll_state.select_last();
+        assert_eq!(scroll_state.selected(), Some(2));
+        assert_eq!(scroll_state.offset(), 0);
+    }
+
+    #[test]
+    fn selection_near_first_row_keeps_offset_at_zero() {
+        let mut scroll_state = ScrollState::new(100, Some(50), 10, 3);
+        assert!(scroll_state.offset() > 0);
+
+        for _ in 0..50 {
+            scroll_state.select_previous();
+        }
+
+        assert_eq!(scroll_state.selected(), Some(0));
+        assert_eq!(scroll_state.offset(), 0);
+    }
+
+    #[test]
+    fn selection_near_last_row_stops_at_max_offset() {
+        let mut scroll_state = ScrollState::new(100, Some(50), 10, 3);
+
+        for _ in 0..50 {
+            scroll_state.select_next();
+        }
+
+        assert_eq!(scroll_state.selected(), Some(99));
+        assert_eq!(scroll_state.offset(), 90);
+    }
+
+    #[test]
+    fn padding_is_kept_while_scrolling_through_the_middle() {
+        let mut scroll_state = ScrollState::new(100, Some(10), 10, 3);
+        scroll_state.select_next();
+
+        // At least 3 rows of padding below the selection within the 10-row viewport.
+        assert_eq!(scroll_state.selected(), Some(11));
+        assert_eq!(scroll_state.offset(), 5);
+    }
+
+    #[test]
+    fn padding_is_clamped_to_half_the_viewport() {
+        // A padding of 100 is clamped down to `max_n_rows_to_display / 2` (5).
+        let scroll_state = ScrollState::new(100, Some(50), 10, 100);
+        assert_eq!(scroll_state.offset(), 46);
+    }
+}
@@ -2,7 +2,10 @@ use anyhow::{Context, Result};
 use crossterm::{
     cursor::{MoveTo, MoveToNextLine},
     style::{Attribute, Color, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
-    terminal::{self, BeginSynchronizedUpdate, Clear, ClearType, EndSynchronizedUpdate},
+    terminal::{
+        self, BeginSynchronizedUpdate, Clear, ClearType, DisableLineWrap, EnableLineWrap,
+        EndSynchronizedUpdate,
+    },
     QueueableCommand,
 };
 use std::{
@@ -22,13 +25,39 @@ use super::scroll_state::ScrollState;
 // +1 for column padding.
 const SPACE: &[u8] = &[b' '; MAX_EXERCISE_NAME_LEN + 1];
 
-fn next_ln(stdout: &mut StdoutLock) -> io::Result<()> {
+// Rows of context kept visible above/below the selection while scrolling. Forks with hundreds
+// of exercises can raise this by constructing `ScrollState` with a different value.
+const DEFAULT_SCROLL_PADDING: usize = 2;
+
+/// Move to the next line, clearing the rest of the current one.
+///
+/// `at_edge` must be `true` when the row's content reaches the terminal's right edge. Some
+/// terminals (e.g. GNOME Terminal) keep auto-wrap enabled and wrap such a row instead of letting
+/// it overflow, which corrupts the whole layout. Writing one more, harmless space first moves the
+/// cursor past the edge before it's cleared, so the wrap never triggers.
+fn next_ln(stdout: &mut StdoutLock, at_edge: bool) -> io::Result<()> {
+    if at_edge {
+        stdout.write_all(b" ")?;
+    }
+
     stdout
         .queue(Clear(ClearType::UntilNewLine))?
         .queue(MoveToNextLine(1))?;
     Ok(())
 }
 
+/// Whether the current terminal is known to render OSC-8 hyperlinks correctly.
+///
+/// Some terminals (notably the VS Code integrated terminal) render them as garbage or not at
+/// all, so we fall back to plain colored paths there.
+fn supports_hyperlinks() -> bool {
+    let is_vscode =
+        std::env::var("TERM_PROGRAM").is_ok_and(|term_program| term_program == "vscode");
+    let hyperlinks_disabled = std::env::var_os("NO_HYPERLINKS").is_some();
+
+    !is_vscode && !hyperlinks_disabled
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Filter {
     Done,
@@ -36,6 +65,12 @@ pub enum Filter {
     None,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Mode {
+    List,
+    Search,
+}
+
 pub struct ListState<'a> {
     /// Footer message to be displayed if not empty.
     pub message: String,
@@ -43,6 +78,10 @@ pub struct ListState<'a> {
     scroll_state: ScrollState,
     name_col_width: usize,
     filter: Filter,
+    mode: Mode,
+    search_query: String,
+    search_query_lower: String,
+    supports_links: bool,
     term_width: u16,
     term_height: u16,
     separator_line: Vec<u8>,
@@ -52,7 +91,9 @@ pub struct ListState<'a> {
 
 impl<'a> ListState<'a> {
     pub fn new(app_state: &'a mut AppState, stdout: &mut StdoutLock) -> io::Result<Self> {
-        stdout.queue(Clear(ClearType::All))?;
+        stdout
+            .queue(Clear(ClearType::All))?
+            .queue(DisableLineWrap)?;
 
         let name_col_title_len = 4;
         let name_col_width = app_state
@@ -67,7 +108,12 @@ impl<'a> ListState<'a> {
         let selected = app_state.current_exercise_ind();
 
         let (width, height) = terminal::size()?;
-        let scroll_state = ScrollState::new(n_rows_with_filter, Some(selected), 5);
+        let scroll_state = ScrollState::new(
+            n_rows_with_filter,
+            Some(selected),
+            5,
+            DEFAULT_SCROLL_PADDING,
+        );
 
         let mut slf = Self {
             message: String::with_capacity(128),
@@ -75,6 +121,10 @@ impl<'a> ListState<'a> {
             scroll_state,
             name_col_width,
             filter,
+            mode: Mode::List,
+            search_query: String::new(),
+            search_query_lower: String::new(),
+            supports_links: supports_hyperlinks(),
             // Set by `set_term_size`
             term_width: 0,
             term_height: 0,
@@ -89,6 +139,11 @@ impl<'a> ListState<'a> {
         Ok(slf)
     }
 
+    /// Re-enables line wrapping. Must be called once when leaving the list view.
+    pub fn teardown(stdout: &mut StdoutLock) -> io::Result<()> {
+        stdout.queue(EnableLineWrap)?.flush()
+    }
+
     pub fn set_term_size(&mut self, width: u16, height: u16) {
         self.term_width = width;
         self.term_height = height;
@@ -164,9 +219,16 @@ impl<'a> ListState<'a> {
             writer.write_str(exercise.name)?;
             writer.write_ascii(&SPACE[..self.name_col_width + 2 - exercise.name.len()])?;
 
-            terminal_file_link(&mut writer, exercise.path, Color::Blue)?;
+            if self.supports_links {
+                terminal_file_link(&mut writer, exercise.path, Color::Blue)?;
+            } else {
+                writer.stdout.queue(SetForegroundColor(Color::Blue))?;
+                writer.write_str(exercise.path)?;
+                writer.stdout.queue(SetForegroundColor(Color::Reset))?;
+            }
 
-            next_ln(stdout)?;
+            let row_at_edge = writer.len() >= self.term_width as usize;
+            next_ln(stdout, row_at_edge)?;
             stdout.queue(ResetColor)?;
             n_displayed_rows += 1;
         }
@@ -186,44 +248,57 @@ impl<'a> ListState<'a> {
         writer.write_ascii(b"  Current  State    Name")?;
         writer.write_ascii(&SPACE[..self.name_col_width - 2])?;
         writer.write_ascii(b"Path")?;
-        next_ln(stdout)?;
+        let header_at_edge = writer.len() >= self.term_width as usize;
+        next_ln(stdout, header_at_edge)?;
 
         // Rows
-        let iter = self.app_state.exercises().iter().enumerate();
-        let n_displayed_rows = match self.filter {
-            Filter::Done => self.draw_rows(stdout, iter.filter(|(_, exercise)| exercise.done))?,
-            Filter::Pending => {
-                self.draw_rows(stdout, iter.filter(|(_, exercise)| !exercise.done))?
-            }
-            Filter::None => self.draw_rows(stdout, iter)?,
-        };
+        let iter = self
+            .app_state
+            .exercises()
+            .iter()
+            .enumerate()
+            .filter(|(_, exercise)| self.is_visible(exercise));
+        let n_displayed_rows = self.draw_rows(stdout, iter)?;
 
         for _ in 0..self.scroll_state.max_n_rows_to_display() - n_displayed_rows {
-            next_ln(stdout)?;
+            next_ln(stdout, false)?;
         }
 
         if self.show_footer {
+            // The separator always spans the full terminal width.
             stdout.write_all(&self.separator_line)?;
-            next_ln(stdout)?;
+            next_ln(stdout, true)?;
 
+            let mut progress_writer = MaxLenWriter::new(stdout, self.term_width as usize);
             progress_bar(
-                &mut MaxLenWriter::new(stdout, self.term_width as usize),
+                &mut progress_writer,
                 self.app_state.n_done(),
                 self.app_state.exercises().len() as u16,
                 self.term_width,
             )?;
-            next_ln(stdout)?;
+            let progress_at_edge = progress_writer.len() >= self.term_width as usize;
+            next_ln(stdout, progress_at_edge)?;
 
             stdout.write_all(&self.separator_line)?;
-            next_ln(stdout)?;
+            next_ln(stdout, true)?;
 
             let mut writer = MaxLenWriter::new(stdout, self.term_width as usize);
-            if self.message.is_empty() {
+            if self.mode == Mode::Search {
+                // Search footer message (replaces the help line while searching)
+                writer.write_ascii(b"/")?;
+                writer
+                    .stdout
+                    .queue(SetForegroundColor(Color::Magenta))?
+                    .queue(SetAttribute(Attribute::Underlined))?;
+                writer.write_str(&self.search_query)?;
+                writer.stdout.queue(ResetColor)?;
+            } else if self.message.is_empty() {
                 // Help footer message
                 if self.scroll_state.selected().is_some() {
                     writer.write_str("↓/j ↑/k home/g end/G | <c>ontinue at | <r>eset exercise")?;
                     if self.narrow_term {
-                        next_ln(stdout)?;
+                        let help_line_at_edge = writer.len() >= self.term_width as usize;
+                        next_ln(stdout, help_line_at_edge)?;
                         writer = MaxLenWriter::new(stdout, self.term_width as usize);
 
                         writer.write_ascii(b"filter ")?;
@@ -262,35 +337,62 @@ impl<'a> ListState<'a> {
                 writer.stdout.queue(SetForegroundColor(Color::Magenta))?;
                 writer.write_str(&self.message)?;
                 stdout.queue(ResetColor)?;
-                next_ln(stdout)?;
+                let message_at_edge = writer.len() >= self.term_width as usize;
+                next_ln(stdout, message_at_edge)?;
             }
 
-            next_ln(stdout)?;
+            // The message branch already terminated its own row above, so this is just a blank
+            // separator line in that case.
+            let is_content_row = self.mode == Mode::Search || self.message.is_empty();
+            let trailing_at_edge = is_content_row && writer.len() >= self.term_width as usize;
+            next_ln(stdout, trailing_at_edge)?;
         }
 
         stdout.queue(EndSynchronizedUpdate)?.flush()
     }
 
-    fn update_rows(&mut self) {
-        let n_rows = match self.filter {
-            Filter::Done => self
-                .app_state
-                .exercises()
-                .iter()
-                .filter(|exercise| exercise.done)
-                .count(),
-            Filter::Pending => self
-                .app_state
-                .exercises()
-                .iter()
-                .filter(|exercise| !exercise.done)
-                .count(),
-            Filter::None => self.app_state.exercises().len(),
+    /// Whether `exercise` passes both the active filter and the search query.
+    fn is_visible(&self, exercise: &Exercise) -> bool {
+        let passes_filter = match self.filter {
+            Filter::Done => exercise.done,
+            Filter::Pending => !exercise.done,
+            Filter::None => true,
         };
 
+        passes_filter
+            && (self.search_query_lower.is_empty()
+                || exercise
+                    .name
+                    .to_lowercase()
+                    .contains(&self.search_query_lower))
+    }
+
+    fn update_rows(&mut self) {
+        let n_rows = self
+            .app_state
+            .exercises()
+            .iter()
+            .filter(|exercise| self.is_visible(exercise))
+            .count();
+
         self.scroll_state.set_n_rows(n_rows);
     }
 
+    /// Snap the selection to the first row matching the current filter and search query.
+    ///
+    /// `ScrollState::selected` is a filtered-row index, so this positions over the
+    /// already-filtered iterator rather than the absolute exercise list.
+    fn snap_selection_to_first_match(&mut self) {
+        let first_match = self
+            .app_state
+            .exercises()
+            .iter()
+            .filter(|exercise| self.is_visible(exercise))
+            .position(|_| true);
+
+        self.scroll_state.select(first_match);
+    }
+
     #[inline]
     pub fn filter(&self) -> Filter {
         self.filter
@@ -301,6 +403,35 @@ impl<'a> ListState<'a> {
         self.update_rows();
     }
 
+    #[inline]
+    pub fn is_searching(&self) -> bool {
+        self.mode == Mode::Search
+    }
+
+    #[inline]
+    pub fn enter_search(&mut self) {
+        self.mode = Mode::Search;
+    }
+
+    pub fn leave_search(&mut self) {
+        self.mode = Mode::List;
+    }
+
+    pub fn push_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.search_query_lower.extend(c.to_lowercase());
+        self.update_rows();
+        self.snap_selection_to_first_match();
+    }
+
+    pub fn pop_search_char(&mut self) {
+        if self.search_query.pop().is_some() {
+            self.search_query_lower = self.search_query.to_lowercase();
+            self.update_rows();
+            self.snap_selection_to_first_match();
+        }
+    }
+
     #[inline]
     pub fn select_next(&mut self) {
         self.scroll_state.select_next();
@@ -322,27 +453,14 @@ impl<'a> ListState<'a> {
     }
 
     fn selected_to_exercise_ind(&self, selected: usize) -> Result<usize> {
-        match self.filter {
-            Filter::Done => self
-                .app_state
-                .exercises()
-                .iter()
-                .enumerate()
-                .filter(|(_, exercise)| exercise.done)
-                .nth(selected)
-                .context("Invalid selection index")
-                .map(|(ind, _)| ind),
-            Filter::Pending => self
-                .app_state
-                .exercises()
-                .iter()
-                .enumerate()
-                .filter(|(_, exercise)| !exercise.done)
-                .nth(selected)
-                .context("Invalid selection index")
-                .map(|(ind, _)| ind),
-            Filter::None => Ok(selected),
-        }
+        self.app_state
+            .exercises()
+            .iter()
+            .enumerate()
+            .filter(|(_, exercise)| self.is_visible(exercise))
+            .nth(selected)
+            .context("Invalid selection index")
+            .map(|(ind, _)| ind)
     }
 
     pub fn reset_selected(&mut self) -> Result<()> {
@@ -0,0 +1,129 @@
+use crossterm::{
+    style::{Color, SetForegroundColor},
+    QueueableCommand,
+};
+use std::io::{self, StdoutLock, Write};
+
+/// A `Write` wrapper that tracks the visible (terminal column) length already written, so a row
+/// can be capped at the terminal width without corrupting ANSI escape sequences.
+pub trait CountedWrite<'a>: Write {
+    fn write_str(&mut self, s: &str) -> io::Result<()>;
+    fn write_ascii(&mut self, ascii: &[u8]) -> io::Result<()>;
+    /// Account for terminal columns written directly to `stdout` that bypass
+    /// `write_str`/`write_ascii` (e.g. a wide glyph written as raw bytes). `n` is the number of
+    /// columns, not bytes.
+    fn add_to_len(&mut self, n: usize);
+    /// The visible length written to this row so far.
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub struct MaxLenWriter<'a, 'b> {
+    pub stdout: &'a mut StdoutLock<'b>,
+    len: usize,
+    max_len: usize,
+}
+
+impl<'a, 'b> MaxLenWriter<'a, 'b> {
+    #[inline]
+    pub fn new(stdout: &'a mut StdoutLock<'b>, max_len: usize) -> Self {
+        Self {
+            stdout,
+            len: 0,
+            max_len,
+        }
+    }
+}
+
+impl Write for MaxLenWriter<'_, '_> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stdout.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdout.flush()
+    }
+}
+
+impl<'a> CountedWrite<'a> for MaxLenWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> io::Result<()> {
+        let remaining_len = self.max_len.saturating_sub(self.len);
+        if remaining_len == 0 {
+            return Ok(());
+        }
+
+        let truncated = if s.chars().count() > remaining_len {
+            s.chars().take(remaining_len).collect::<String>()
+        } else {
+            s.to_owned()
+        };
+
+        self.len += truncated.chars().count();
+        self.stdout.write_all(truncated.as_bytes())
+    }
+
+    fn write_ascii(&mut self, ascii: &[u8]) -> io::Result<()> {
+        let remaining_len = self.max_len.saturating_sub(self.len);
+        let n = ascii.len().min(remaining_len);
+
+        self.len += n;
+        self.stdout.write_all(&ascii[..n])
+    }
+
+    #[inline]
+    fn add_to_len(&mut self, n: usize) {
+        self.len += n;
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+pub fn progress_bar(
+    writer: &mut MaxLenWriter,
+    n_done: u16,
+    n_total: u16,
+    term_width: u16,
+) -> io::Result<()> {
+    const PREFIX: &[u8] = b"Progress: [";
+    const PREFIX_LEN: u16 = PREFIX.len() as u16;
+    const POSTFIX_LEN: u16 = "] xxx/xxx".len() as u16;
+    const WRAPPER_LEN: u16 = PREFIX_LEN + POSTFIX_LEN;
+
+    writer.write_ascii(PREFIX)?;
+
+    if term_width <= WRAPPER_LEN {
+        writer.write_ascii(b"]")?;
+        return Ok(());
+    }
+
+    let width = term_width - WRAPPER_LEN;
+    let filled = (width * n_done.min(n_total)) / n_total.max(1);
+
+    writer.stdout.queue(SetForegroundColor(Color::Green))?;
+    for _ in 0..filled {
+        writer.write_ascii(b"#")?;
+    }
+
+    writer.stdout.queue(SetForegroundColor(Color::Reset))?;
+    for _ in filled..width {
+        writer.write_ascii(b"-")?;
+    }
+
+    write!(writer, "] {n_done:>3}/{n_total:>3}")
+}
+
+pub fn terminal_file_link(writer: &mut MaxLenWriter, path: &str, color: Color) -> io::Result<()> {
+    writer.stdout.queue(SetForegroundColor(color))?;
+    write!(writer, "\x1b]8;;file://{path}\x1b\\")?;
+    writer.write_str(path)?;
+    write!(writer, "\x1b]8;;\x1b\\")?;
+    writer.stdout.queue(SetForegroundColor(Color::Reset))?;
+    Ok(())
+}